@@ -52,6 +52,29 @@
 //! % cargo check --workspace
 //! ```
 //!
+//! ## Counting
+//!
+//! A flag that can be repeated on the command line, decoded into how many times it was given
+//! instead of a single `bool`. See [`Named::count`].
+//!
+//! For example `rsync` takes a repeatable `-v` to raise its verbosity:
+//! ```txt
+//! % rsync -vvv
+//! ```
+//!
+//! ## Negation
+//!
+//! A switch that also accepts an auto-derived `--no-<name>` form to explicitly turn it off,
+//! with whichever form appears later on the command line taking precedence. See
+//! [`Named::negatable_switch`].
+//!
+//! For example `npm` accepts both `--audit` and `--no-audit`:
+//! ```txt
+//! % npm install --no-audit
+//! ```
+//!
+use std::cell::Cell;
+use std::env;
 use std::ffi::OsString;
 
 use super::{Args, Error, Item, OptionParser, Parser, Rc};
@@ -66,6 +89,29 @@ pub struct Named {
     short: Vec<char>,
     long: Vec<&'static str>,
     help: Option<String>,
+    env: Option<&'static str>,
+    value_hint: Option<ValueHint>,
+}
+
+/// A hint about the kind of value an argument or positional expects
+///
+/// `bpaf` does not use this information itself, it only records it on the corresponding
+/// [`Item`] so [`shell_completion_script`] can generate more useful completions than a bare
+/// word list - mirrors clap's `ValueHint`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValueHint {
+    /// Value is a path to a file
+    FilePath,
+    /// Value is a path to a directory
+    DirPath,
+    /// Value is a hostname
+    Hostname,
+    /// Value is a username
+    Username,
+    /// Value is the name of a command on `$PATH`
+    CommandName,
+    /// None of the above, no special completion is available
+    Other,
 }
 
 /// A flag/switch/argument that has a short name
@@ -89,6 +135,8 @@ pub fn short(short: char) -> Named {
         short: vec![short],
         long: Vec::new(),
         help: None,
+        env: None,
+        value_hint: None,
     }
 }
 
@@ -113,6 +161,8 @@ pub fn long(long: &'static str) -> Named {
         short: Vec::new(),
         long: vec![long],
         help: None,
+        env: None,
+        value_hint: None,
     }
 }
 
@@ -179,6 +229,37 @@ impl Named {
         self
     }
 
+    /// Fall back to an environment variable when the flag/switch/argument is absent
+    ///
+    /// If the option is not present on a command line `bpaf` reads its value from the given
+    /// environment variable instead. Precedence is: value taken from the command line, then the
+    /// environment variable (if set), then whatever fallback the parser was given otherwise -
+    /// `absent` value for flags/switches or [`fallback`][Parser::fallback] for arguments.
+    ///
+    /// ```rust
+    /// # use bpaf::*;
+    /// let db_url: Parser<String> = long("db-url").env("DATABASE_URL").argument("URL");
+    /// # drop(db_url);
+    /// ```
+    #[must_use]
+    pub fn env(mut self, variable: &'static str) -> Self {
+        self.env = Some(variable);
+        self
+    }
+
+    /// Hint the kind of value this argument expects, for shell completion
+    ///
+    /// ```rust
+    /// # use bpaf::*;
+    /// let path = short('o').long("output").value_hint(ValueHint::FilePath).argument("FILE");
+    /// # drop(path);
+    /// ```
+    #[must_use]
+    pub fn value_hint(mut self, hint: ValueHint) -> Self {
+        self.value_hint = Some(hint);
+        self
+    }
+
     /// Simple boolean flag
     ///
     /// Parser produces `true` if flag is present in a command line or `false` otherwise
@@ -193,7 +274,7 @@ impl Named {
     /// ```
     #[must_use]
     pub fn switch(self) -> Parser<bool> {
-        build_flag_parser(true, Some(false), self.short, self.long, self.help)
+        build_flag_parser(true, Some(false), self.short, self.long, self.help, self.env)
     }
 
     /// Flag with custom present/absent values
@@ -218,7 +299,7 @@ impl Named {
     where
         T: Clone + 'static,
     {
-        build_flag_parser(present, Some(absent), self.short, self.long, self.help)
+        build_flag_parser(present, Some(absent), self.short, self.long, self.help, self.env)
     }
 
     /// Required flag with custom value
@@ -241,19 +322,46 @@ impl Named {
     /// # drop(state);
     /// ```
     ///
+    #[must_use]
+    pub fn req_flag<T>(self, present: T) -> Parser<T>
+    where
+        T: Clone + 'static,
+    {
+        build_flag_parser(present, None, self.short, self.long, self.help, self.env)
+    }
+
+    /// Count how many times a flag is given on a command line
+    ///
+    /// Replaces the `short('v').req_flag(()).many().map(|v| v.len())` idiom with a single
+    /// call, so `-vvv` style verbosity counters become a one-liner. Same as [`switch`][Named::switch]
+    /// and friends, [`env`][Named::env] is checked when the flag is absent from the command line -
+    /// a truthy value counts as one occurrence.
+    ///
     /// ```rust
     /// # use bpaf::*;
-    /// // counts how many times flag `-v` is given on a command line
-    /// let verbosity: Parser<usize> = short('v').req_flag(()).many().map(|v| v.len());
+    /// let verbosity: Parser<usize> = short('v').help("Increase verbosity").count();
     /// # drop(verbosity);
     /// ```
+    #[must_use]
+    pub fn count(self) -> Parser<usize> {
+        build_count_parser(self.short, self.long, self.help, self.env)
+    }
+
+    /// Boolean switch that also accepts an auto-derived `--no-<name>` negation
     ///
+    /// For every long name registered this also accepts `--no-<name>` to explicitly set the
+    /// value to `false`. When both the positive and negative forms are given, whichever one
+    /// appears later on the command line wins. Same as [`switch`][Named::switch], [`env`][Named::env]
+    /// is checked when neither form is present on the command line.
+    ///
+    /// ```rust
+    /// # use bpaf::*;
+    /// let feature: Parser<bool> = long("feature").negatable_switch();
+    /// # drop(feature);
+    /// ```
     #[must_use]
-    pub fn req_flag<T>(self, present: T) -> Parser<T>
-    where
-        T: Clone + 'static,
-    {
-        build_flag_parser(present, None, self.short, self.long, self.help)
+    pub fn negatable_switch(self) -> Parser<bool> {
+        build_negatable_flag_parser(self.short, self.long, self.help, self.env)
     }
 
     /// Named argument that can be encoded as String
@@ -269,7 +377,7 @@ impl Named {
     /// ```
     #[must_use]
     pub fn argument(self, metavar: &'static str) -> Parser<String> {
-        build_argument(self.short, self.long, self.help, metavar)
+        build_argument(self.short, self.long, self.help, metavar, self.env, None, self.value_hint)
             .parse(|x| x.utf8.ok_or("not utf8")) // TODO - provide a better diagnostic
     }
 
@@ -286,12 +394,42 @@ impl Named {
     /// ```
     #[must_use]
     pub fn argument_os(self, metavar: &'static str) -> Parser<OsString> {
-        build_argument(self.short, self.long, self.help, metavar).map(|x| x.os)
+        build_argument(self.short, self.long, self.help, metavar, self.env, None, self.value_hint).map(|x| x.os)
+    }
+
+    /// Named argument parsed directly into `T` via [`FromStr`]
+    ///
+    /// Produces a proper [`Error`] naming the metavar and the underlying [`FromStr`] error
+    /// instead of the generic `"not utf8"` diagnostic `argument` falls back to for custom
+    /// `.parse` closures. Combine with [`range`][Parser::range] to additionally restrict `T` to
+    /// a numeric interval.
+    ///
+    /// ```rust
+    /// # use bpaf::*;
+    /// let port: Parser<u16> = long("port").argument_with("PORT");
+    /// # drop(port);
+    /// ```
+    #[must_use]
+    pub fn argument_with<T>(self, metavar: &'static str) -> Parser<T>
+    where
+        T: std::str::FromStr + 'static,
+        T::Err: std::fmt::Display,
+    {
+        build_argument(self.short, self.long, self.help, metavar, self.env, None, self.value_hint)
+            .parse(move |w| w.utf8.ok_or_else(|| format!("{} is not utf8", metavar)))
+            .parse(move |value| {
+                value
+                    .parse::<T>()
+                    .map_err(|e| format!("can't parse {} as {}: {}", value, metavar, e))
+            })
     }
 }
 
 /// Positional argument that can be encoded as String
 ///
+/// Restrict it to one of a fixed set of values with [`Parser::possible_values`]:
+/// `positional("SPEED").possible_values(&["fast", "slow"])`.
+///
 /// ```rust
 /// # use bpaf::*;
 /// let arg: Parser<String> = positional("INPUT");
@@ -299,7 +437,43 @@ impl Named {
 /// ```
 #[must_use]
 pub fn positional(metavar: &'static str) -> Parser<String> {
-    build_positional(metavar).parse(|x| x.utf8.ok_or("not utf8")) // TODO - provide a better diagnostic
+    build_positional(metavar, None, None).parse(|x| x.utf8.ok_or("not utf8")) // TODO - provide a better diagnostic
+}
+
+/// Positional argument parsed directly into `T` via [`FromStr`]
+///
+/// See [`Named::argument_with`] for the named-argument equivalent.
+///
+/// ```rust
+/// # use bpaf::*;
+/// let port: Parser<u16> = positional_with("PORT");
+/// # drop(port);
+/// ```
+#[must_use]
+pub fn positional_with<T>(metavar: &'static str) -> Parser<T>
+where
+    T: std::str::FromStr + 'static,
+    T::Err: std::fmt::Display,
+{
+    build_positional(metavar, None, None)
+        .parse(move |w| w.utf8.ok_or_else(|| format!("{} is not utf8", metavar)))
+        .parse(move |value| {
+            value
+                .parse::<T>()
+                .map_err(|e| format!("can't parse {} as {}: {}", value, metavar, e))
+        })
+}
+
+/// Positional argument with a [`ValueHint`] for shell completion
+///
+/// ```rust
+/// # use bpaf::*;
+/// let arg: Parser<String> = positional_with_hint("FILE", ValueHint::FilePath);
+/// # drop(arg)
+/// ```
+#[must_use]
+pub fn positional_with_hint(metavar: &'static str, hint: ValueHint) -> Parser<String> {
+    build_positional(metavar, None, Some(hint)).parse(|x| x.utf8.ok_or("not utf8"))
 }
 
 /// Positional argument that can be encoded as String and will be taken only if check passes
@@ -337,7 +511,7 @@ where
 /// ```
 #[must_use]
 pub fn positional_os(metavar: &'static str) -> Parser<OsString> {
-    build_positional(metavar).map(|x| x.os)
+    build_positional(metavar, None, None).map(|x| x.os)
 }
 
 /// Subcommand parser
@@ -386,6 +560,13 @@ where
         metavar: None,
         help: help.map(Into::into),
         kind: ItemKind::Command,
+        env: None,
+        possible_values: None,
+        value_hint: None,
+        repeatable: false,
+        negatable: None,
+        bounds: None,
+        subcommand: Some(subparser.meta.collect_items()),
     });
     let meta2 = meta.clone();
     let parse = move |mut args: Args| {
@@ -402,16 +583,319 @@ where
     }
 }
 
+/// Target shell for [`shell_completion_script`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shell {
+    /// Generate a `bash` completion script, to be `source`d from `.bashrc` or
+    /// `/etc/bash_completion.d`
+    Bash,
+    /// Generate a `zsh` completion script, to be placed somewhere on `$fpath`
+    Zsh,
+    /// Generate a `fish` completion script, to be placed in `~/.config/fish/completions`
+    Fish,
+}
+
+impl<T> OptionParser<T> {
+    /// Generate a shell completion script for this parser
+    ///
+    /// Walks the parser's [`Meta`] tree via [`Meta::collect_items`], and for every
+    /// [`ItemKind::Command`] recurses into the subcommand's own items (recorded on the
+    /// [`Item`] by [`command`]), nesting the generated completions under the subcommand's name.
+    ///
+    /// ```rust
+    /// # use bpaf::*;
+    /// let ws = long("workspace").switch();
+    /// let opt: OptionParser<bool> = Info::default().for_parser(ws);
+    /// let script = opt.shell_completion_script(Shell::Bash, "myapp");
+    /// # drop(script);
+    /// ```
+    #[must_use]
+    pub fn shell_completion_script(&self, shell: Shell, prog: &str) -> String {
+        shell_completion_script(shell, prog, &self.meta.collect_items())
+    }
+}
+
+/// Generate a shell completion script for a flat set of options
+///
+/// `prog` is the name of the binary as typed by the user, `items` are the flags, arguments and
+/// positionals collected from a [`Meta`] tree, typically via [`OptionParser::shell_completion_script`].
+/// [`ItemKind::Command`] items recurse into the [`Item::subcommand`] items recorded by
+/// [`command`] and nest the result under the subcommand's name.
+/// [`ValueHint::FilePath`]/[`ValueHint::DirPath`] drive file/directory completion and
+/// `possible_values` complete to that fixed word list. zsh and fish attach both to the flag or
+/// argument they belong to; bash adds `possible_values` to the script's single word list and
+/// falls back to file or directory completion for the whole script when no word matches.
+/// zsh emits no spec for positionals, so their hints and `possible_values` are not used there.
+#[must_use]
+pub fn shell_completion_script(shell: Shell, prog: &str, items: &[Item]) -> String {
+    match shell {
+        Shell::Bash => bash_completion_script(prog, items),
+        Shell::Zsh => zsh_completion_script(prog, items),
+        Shell::Fish => fish_completion_script(prog, items, None),
+    }
+}
+
+fn item_names(item: &Item) -> Vec<String> {
+    let mut names = Vec::new();
+    if let Some(long) = item.long {
+        names.push(format!("--{}", long));
+    }
+    if let Some(short) = item.short {
+        names.push(format!("-{}", short));
+    }
+    names
+}
+
+/// Escape a `'` for safe interpolation into a single-quoted shell string literal
+fn shell_quote_escape(s: &str) -> String {
+    s.replace('\'', r"'\''")
+}
+
+fn bash_wants_hint(items: &[Item], hint: ValueHint) -> bool {
+    items.iter().any(|item| {
+        if item.value_hint == Some(hint) {
+            return true;
+        }
+        match &item.subcommand {
+            Some(sub_items) => bash_wants_hint(sub_items, hint),
+            None => false,
+        }
+    })
+}
+
+fn bash_completion_script(prog: &str, items: &[Item]) -> String {
+    let opt_flag = if bash_wants_hint(items, ValueHint::FilePath) {
+        " -o default"
+    } else if bash_wants_hint(items, ValueHint::DirPath) {
+        " -o dirnames"
+    } else {
+        ""
+    };
+    format!(
+        "_{prog}() {{\n    local cur=\"${{COMP_WORDS[COMP_CWORD]}}\"\n{body}}}\ncomplete -F _{prog}{opt_flag} {prog}\n",
+        prog = prog,
+        body = bash_dispatch_body(items, 1, "    "),
+        opt_flag = opt_flag,
+    )
+}
+
+/// Build the body of the `_{prog}` completion function, branching on `COMP_WORDS[depth]` when
+/// `items` contains subcommands with their own nested items.
+fn bash_dispatch_body(items: &[Item], depth: usize, indent: &str) -> String {
+    let mut words = Vec::new();
+    let mut cases = String::new();
+    for item in items {
+        match item.kind {
+            ItemKind::Command => {
+                if let Some(name) = item.long {
+                    words.push(name.to_string());
+                    if let Some(sub_items) = &item.subcommand {
+                        let sub_indent = format!("{}        ", indent);
+                        cases.push_str(&format!(
+                            "{indent}        {name})\n{body}{indent}            ;;\n",
+                            indent = indent,
+                            name = name,
+                            body = bash_dispatch_body(sub_items, depth + 1, &sub_indent),
+                        ));
+                    }
+                }
+            }
+            ItemKind::Flag | ItemKind::Positional => {
+                words.extend(item_names(item));
+                if let Some(values) = item.possible_values {
+                    words.extend(values.iter().map(|v| (*v).to_string()));
+                }
+            }
+        }
+    }
+    if cases.is_empty() {
+        format!(
+            "{indent}COMPREPLY=($(compgen -W \"{words}\" -- \"$cur\"))\n",
+            indent = indent,
+            words = words.join(" "),
+        )
+    } else {
+        format!(
+            "{indent}case \"${{COMP_WORDS[{depth}]}}\" in\n{cases}{indent}    *)\n{indent}        COMPREPLY=($(compgen -W \"{words}\" -- \"$cur\"))\n{indent}        ;;\n{indent}esac\n",
+            indent = indent,
+            depth = depth,
+            cases = cases,
+            words = words.join(" "),
+        )
+    }
+}
+
+fn zsh_value_hint_action(item: &Item) -> Option<String> {
+    if let Some(values) = item.possible_values {
+        return Some(format!(
+            ":{}:({})",
+            item.metavar.unwrap_or("ARG"),
+            values.join(" ")
+        ));
+    }
+    match item.value_hint {
+        Some(ValueHint::FilePath) => Some(":FILE:_files".to_string()),
+        Some(ValueHint::DirPath) => Some(":DIR:_directories".to_string()),
+        Some(ValueHint::Hostname) => Some(":HOST:_hosts".to_string()),
+        Some(ValueHint::Username) => Some(":USER:_users".to_string()),
+        Some(ValueHint::CommandName) => Some(":CMD:_command_names -e".to_string()),
+        _ => None,
+    }
+}
+
+fn zsh_completion_script(prog: &str, items: &[Item]) -> String {
+    format!(
+        "#compdef {prog}\n_{prog}() {{\n{body}}}\n",
+        prog = prog,
+        body = zsh_arguments_block(items, "    "),
+    )
+}
+
+/// Build an `_arguments` invocation for `items`, dispatching into a `case $words[1] in ...`
+/// block for each subcommand's own nested `_arguments` invocation.
+fn zsh_arguments_block(items: &[Item], indent: &str) -> String {
+    let mut specs = Vec::new();
+    let mut sub_names = Vec::new();
+    let mut sub_cases = String::new();
+    for item in items {
+        match item.kind {
+            ItemKind::Command => {
+                if let Some(name) = item.long {
+                    let name = shell_quote_escape(name);
+                    sub_names.push(name.clone());
+                    let inner = match &item.subcommand {
+                        Some(sub_items) => {
+                            zsh_arguments_block(sub_items, &format!("{}            ", indent))
+                        }
+                        None => format!("{indent}            true\n", indent = indent),
+                    };
+                    sub_cases.push_str(&format!(
+                        "{indent}        {name})\n{inner}{indent}            ;;\n",
+                        indent = indent,
+                        name = name,
+                        inner = inner,
+                    ));
+                }
+            }
+            ItemKind::Flag | ItemKind::Positional => {
+                let action = zsh_value_hint_action(item).unwrap_or_default();
+                let help = shell_quote_escape(item.help.as_deref().unwrap_or(""));
+                for name in item_names(item) {
+                    specs.push(format!(
+                        "{indent}    '{}[{}]{}'",
+                        name, help, action
+                    ));
+                }
+            }
+        }
+    }
+    let mut out = format!("{indent}_arguments -C \\\n", indent = indent);
+    for spec in &specs {
+        out.push_str(spec);
+        out.push_str(" \\\n");
+    }
+    if sub_names.is_empty() {
+        out.push_str(&format!("{indent}    '*:arg:_default'\n", indent = indent));
+    } else {
+        out.push_str(&format!(
+            "{indent}    '1: :({names})' \\\n{indent}    '*::arg:->args'\n",
+            indent = indent,
+            names = sub_names.join(" "),
+        ));
+        out.push_str(&format!(
+            "{indent}case $state in\n{indent}    args)\n{indent}        case $words[1] in\n",
+            indent = indent
+        ));
+        out.push_str(&sub_cases);
+        out.push_str(&format!(
+            "{indent}        esac\n{indent}        ;;\n{indent}esac\n",
+            indent = indent
+        ));
+    }
+    out
+}
+
+fn fish_completion_script(prog: &str, items: &[Item], condition: Option<&str>) -> String {
+    let mut lines = Vec::new();
+    for item in items {
+        match item.kind {
+            ItemKind::Command => {
+                if let Some(name) = item.long {
+                    let name = shell_quote_escape(name);
+                    let line = match condition {
+                        Some(cond) => format!(
+                            "complete -c {prog} -f -n '__fish_seen_subcommand_from {cond}' -a '{name}'",
+                            prog = prog,
+                            cond = shell_quote_escape(cond),
+                            name = name
+                        ),
+                        None => format!(
+                            "complete -c {prog} -f -n '__fish_use_subcommand' -a '{name}'",
+                            prog = prog,
+                            name = name
+                        ),
+                    };
+                    lines.push(line);
+                    if let Some(sub_items) = &item.subcommand {
+                        lines.push(fish_completion_script(prog, sub_items, Some(name.as_str())));
+                    }
+                }
+            }
+            ItemKind::Flag | ItemKind::Positional => {
+                let mut line = format!("complete -c {}", prog);
+                if let Some(cond) = condition {
+                    line.push_str(&format!(
+                        " -n '__fish_seen_subcommand_from {}'",
+                        shell_quote_escape(cond)
+                    ));
+                }
+                if let Some(short) = item.short {
+                    line.push_str(&format!(" -s {}", short));
+                }
+                if let Some(long) = item.long {
+                    line.push_str(&format!(" -l {}", long));
+                }
+                match item.value_hint {
+                    Some(ValueHint::FilePath) => line.push_str(" -r -F"),
+                    Some(ValueHint::DirPath) => line.push_str(" -r -a '(__fish_complete_directories)'"),
+                    _ => {}
+                }
+                if let Some(values) = item.possible_values {
+                    line.push_str(&format!(" -r -a '{}'", values.join(" ")));
+                }
+                if let Some(help) = &item.help {
+                    line.push_str(&format!(" -d '{}'", shell_quote_escape(help)));
+                }
+                lines.push(line);
+            }
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
 fn short_or_long_flag(arg: &Arg, shorts: &[char], longs: &[&str]) -> bool {
     shorts.iter().any(|&c| arg.is_short(c)) || longs.iter().any(|s| arg.is_long(s))
 }
 
+/// Check if an environment variable value should be treated as a flag being present
+///
+/// Follows the same loose conventions as most CI systems: `1`/`true`/`yes`/`on` (in any
+/// casing, surrounding whitespace ignored) count as truthy, anything else (including an empty
+/// string) does not.
+fn env_flag_truthy(value: &std::ffi::OsStr) -> bool {
+    match value.to_str() {
+        Some(s) => matches!(s.trim().to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"),
+        None => false,
+    }
+}
+
 fn build_flag_parser<T>(
     present: T,
     absent: Option<T>,
     shorts: Vec<char>,
     longs: Vec<&'static str>,
     help: Option<String>,
+    env: Option<&'static str>,
 ) -> Parser<T>
 where
     T: Clone + 'static,
@@ -422,6 +906,13 @@ where
         metavar: None,
         help,
         kind: ItemKind::Flag,
+        env,
+        possible_values: None,
+        value_hint: None,
+        repeatable: false,
+        negatable: None,
+        bounds: None,
+        subcommand: None,
     };
     let required = absent.is_none();
     let meta = item.required(required);
@@ -434,13 +925,19 @@ where
 
     let parse = move |mut args: Args| {
         if args.take_flag(|arg| short_or_long_flag(arg, &shorts, &longs)) {
-            Ok((present.clone(), args))
-        } else {
-            Ok((
-                absent.as_ref().ok_or_else(|| missing.clone())?.clone(),
-                args,
-            ))
+            return Ok((present.clone(), args));
+        }
+        if let Some(name) = env {
+            if let Some(val) = env::var_os(name) {
+                if env_flag_truthy(&val) {
+                    return Ok((present.clone(), args));
+                }
+            }
         }
+        Ok((
+            absent.as_ref().ok_or_else(|| missing.clone())?.clone(),
+            args,
+        ))
     };
     Parser {
         parse: Rc::new(parse),
@@ -448,11 +945,130 @@ where
     }
 }
 
+fn build_count_parser(
+    shorts: Vec<char>,
+    longs: Vec<&'static str>,
+    help: Option<String>,
+    env: Option<&'static str>,
+) -> Parser<usize> {
+    let item = Item {
+        short: shorts.first().copied(),
+        long: longs.first().copied(),
+        metavar: None,
+        help,
+        kind: ItemKind::Flag,
+        env,
+        possible_values: None,
+        value_hint: None,
+        repeatable: true,
+        negatable: None,
+        bounds: None,
+        subcommand: None,
+    };
+    let meta = item.required(false);
+
+    let parse = move |mut args: Args| {
+        let mut count = 0usize;
+        while args.take_flag(|arg| short_or_long_flag(arg, &shorts, &longs)) {
+            count += 1;
+        }
+        if count == 0 {
+            if let Some(name) = env {
+                if let Some(val) = env::var_os(name) {
+                    if env_flag_truthy(&val) {
+                        count = 1;
+                    }
+                }
+            }
+        }
+        Ok((count, args))
+    };
+    Parser {
+        parse: Rc::new(parse),
+        meta,
+    }
+}
+
+fn build_negatable_flag_parser(
+    shorts: Vec<char>,
+    longs: Vec<&'static str>,
+    help: Option<String>,
+    env: Option<&'static str>,
+) -> Parser<bool> {
+    let negations: Vec<String> = longs.iter().map(|l| format!("no-{}", l)).collect();
+    let item = Item {
+        short: shorts.first().copied(),
+        long: longs.first().copied(),
+        metavar: None,
+        help,
+        kind: ItemKind::Flag,
+        env,
+        possible_values: None,
+        value_hint: None,
+        repeatable: false,
+        negatable: negations.first().cloned(),
+        bounds: None,
+        subcommand: None,
+    };
+    let meta = item.required(false);
+
+    let parse = move |mut args: Args| {
+        let last = Cell::new(None);
+        loop {
+            let matched = args.take_flag(|arg| {
+                if short_or_long_flag(arg, &shorts, &longs) {
+                    last.set(Some(true));
+                    true
+                } else if negations.iter().any(|n| arg.is_long(n)) {
+                    last.set(Some(false));
+                    true
+                } else {
+                    false
+                }
+            });
+            if !matched {
+                break;
+            }
+        }
+        if last.get().is_none() {
+            if let Some(name) = env {
+                if let Some(val) = env::var_os(name) {
+                    if env_flag_truthy(&val) {
+                        last.set(Some(true));
+                    }
+                }
+            }
+        }
+        Ok((last.get().unwrap_or(false), args))
+    };
+    Parser {
+        parse: Rc::new(parse),
+        meta,
+    }
+}
+
+/// Check that `value` is one of `values`, producing the same diagnostic shape used for
+/// restricted arguments and positionals.
+fn check_possible_value(value: String, values: &'static [&'static str]) -> Result<String, String> {
+    if values.contains(&value.as_str()) {
+        Ok(value)
+    } else {
+        Err(format!(
+            "invalid value '{}', expected one of: {}",
+            value,
+            values.join(", ")
+        ))
+    }
+}
+
 fn build_argument(
     shorts: Vec<char>,
     longs: Vec<&'static str>,
     help: Option<String>,
     metavar: &'static str,
+    env: Option<&'static str>,
+    possible_values: Option<&'static [&'static str]>,
+    value_hint: Option<ValueHint>,
 ) -> Parser<Word> {
     let item = Item {
         kind: ItemKind::Flag,
@@ -460,16 +1076,30 @@ fn build_argument(
         long: longs.first().copied(),
         metavar: Some(metavar),
         help,
+        env,
+        possible_values,
+        value_hint,
+        repeatable: false,
+        negatable: None,
+        bounds: None,
+        subcommand: None,
     };
     let meta = item.required(true);
     let meta2 = meta.clone();
     let parse = move |mut args: Args| {
-        #[allow(clippy::option_if_let_else)]
         if let Some(w) = args.take_arg(|arg| short_or_long_flag(arg, &shorts, &longs))? {
-            Ok((w, args))
-        } else {
-            Err(Error::Missing(vec![meta2.clone()]))
+            return Ok((w, args));
+        }
+        if let Some(name) = env {
+            if let Some(os) = env::var_os(name) {
+                let word = Word {
+                    utf8: os.clone().into_string().ok(),
+                    os,
+                };
+                return Ok((word, args));
+            }
         }
+        Err(Error::Missing(vec![meta2.clone()]))
     };
 
     Parser {
@@ -478,13 +1108,24 @@ fn build_argument(
     }
 }
 
-fn build_positional(metavar: &'static str) -> Parser<Word> {
+fn build_positional(
+    metavar: &'static str,
+    possible_values: Option<&'static [&'static str]>,
+    value_hint: Option<ValueHint>,
+) -> Parser<Word> {
     let item = Item {
         short: None,
         long: None,
         metavar: Some(metavar),
         help: None,
         kind: ItemKind::Positional,
+        env: None,
+        possible_values,
+        value_hint,
+        repeatable: false,
+        negatable: None,
+        bounds: None,
+        subcommand: None,
     };
     let meta = item.required(true);
     let meta2 = meta.clone();
@@ -509,6 +1150,13 @@ where
         metavar: Some(metavar),
         help: None,
         kind: ItemKind::Positional,
+        env: None,
+        possible_values: None,
+        value_hint: None,
+        repeatable: false,
+        negatable: None,
+        bounds: None,
+        subcommand: None,
     };
     let meta = item.required(false);
     let meta2 = meta.clone();
@@ -532,3 +1180,256 @@ where
         meta,
     }
 }
+
+impl Parser<String> {
+    /// Restrict a parsed value to one of a fixed set of values
+    ///
+    /// Fails with a message listing the allowed choices when the value parsed so far isn't one
+    /// of `values`. The choices are also recorded on the underlying [`Item`] so usage/help can
+    /// list them. Meant to be chained after [`Named::argument`]/[`positional`].
+    ///
+    /// ```rust
+    /// # use bpaf::*;
+    /// let speed = short('s').long("speed").argument("SPEED").possible_values(&["fast", "slow"]);
+    /// # drop(speed)
+    /// ```
+    #[must_use]
+    pub fn possible_values(self, values: &'static [&'static str]) -> Parser<String> {
+        let mut meta = self.meta.clone();
+        meta.set_possible_values(values);
+        Parser {
+            meta,
+            ..self.parse(move |value| check_possible_value(value, values))
+        }
+    }
+}
+
+impl<T> Parser<T>
+where
+    T: PartialOrd + std::fmt::Display + Clone + 'static,
+{
+    /// Restrict a parsed value to a numeric range
+    ///
+    /// Fails with `"NN not in range A..=B"` when the value parsed so far falls outside
+    /// `bounds`. Meant to be chained after [`Named::argument_with`]/[`positional_with`]. The
+    /// bounds are also recorded on the underlying [`Item`] so help/usage can display the
+    /// accepted interval, same as [`Parser::possible_values`] records its accepted choices.
+    ///
+    /// ```rust
+    /// # use bpaf::*;
+    /// let port: Parser<u16> = long("port").argument_with("PORT").range(1024..=65535);
+    /// # drop(port);
+    /// ```
+    #[must_use]
+    pub fn range(self, bounds: std::ops::RangeInclusive<T>) -> Parser<T> {
+        let lo = bounds.start().to_string();
+        let hi = bounds.end().to_string();
+        let mut meta = self.meta.clone();
+        meta.set_bounds(lo, hi);
+        Parser {
+            meta,
+            ..self.parse(move |value| {
+                if bounds.contains(&value) {
+                    Ok(value)
+                } else {
+                    Err(format!(
+                        "{} not in range {}..={}",
+                        value,
+                        bounds.start(),
+                        bounds.end()
+                    ))
+                }
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(words: &[&str]) -> Args {
+        Args::from(words)
+    }
+
+    /// Explicit CLI value wins even when the fallback env var is also set.
+    #[test]
+    fn env_fallback_cli_takes_precedence() {
+        env::set_var("BPAF_TEST_CLI_WINS", "env-value");
+        let parser = long("db-url").env("BPAF_TEST_CLI_WINS").argument("URL");
+        let (value, _) = (parser.parse)(args(&["--db-url", "cli-value"])).unwrap();
+        assert_eq!(value, "cli-value");
+        env::remove_var("BPAF_TEST_CLI_WINS");
+    }
+
+    /// Env var is used when the option is absent from the command line.
+    #[test]
+    fn env_fallback_used_when_cli_absent() {
+        env::set_var("BPAF_TEST_ENV_USED", "env-value");
+        let parser = long("db-url").env("BPAF_TEST_ENV_USED").argument("URL");
+        let (value, _) = (parser.parse)(args(&[])).unwrap();
+        assert_eq!(value, "env-value");
+        env::remove_var("BPAF_TEST_ENV_USED");
+    }
+
+    /// Neither CLI nor env var present: falls through to the parser's own fallback/absent.
+    #[test]
+    fn env_fallback_falls_back_when_both_absent() {
+        env::remove_var("BPAF_TEST_NEITHER");
+        let parser = long("flag").env("BPAF_TEST_NEITHER").switch();
+        let (value, _) = (parser.parse)(args(&[])).unwrap();
+        assert!(!value);
+    }
+
+    /// A truthy env value (case-insensitive) is enough to flip a switch/flag to present.
+    #[test]
+    fn env_truthy_values_flip_a_switch() {
+        for truthy in ["1", "true", "TRUE", "yes", "on"] {
+            env::set_var("BPAF_TEST_TRUTHY", truthy);
+            let parser = long("flag").env("BPAF_TEST_TRUTHY").switch();
+            let (value, _) = (parser.parse)(args(&[])).unwrap();
+            assert!(value, "{:?} should be truthy", truthy);
+        }
+        env::remove_var("BPAF_TEST_TRUTHY");
+    }
+
+    /// Each occurrence of the flag on the command line bumps the count by one.
+    #[test]
+    fn count_tallies_every_occurrence() {
+        let parser = short('v').count();
+        let (value, _) = (parser.parse)(args(&["-v", "-v", "-v"])).unwrap();
+        assert_eq!(value, 3);
+    }
+
+    /// Absent from the command line and no env fallback: counts as zero.
+    #[test]
+    fn count_is_zero_when_absent() {
+        let parser = short('v').count();
+        let (value, _) = (parser.parse)(args(&[])).unwrap();
+        assert_eq!(value, 0);
+    }
+
+    /// A truthy env value counts as a single occurrence when the flag is absent.
+    #[test]
+    fn count_env_fallback_counts_as_one() {
+        env::set_var("BPAF_TEST_COUNT_ENV", "1");
+        let parser = short('v').env("BPAF_TEST_COUNT_ENV").count();
+        let (value, _) = (parser.parse)(args(&[])).unwrap();
+        assert_eq!(value, 1);
+        env::remove_var("BPAF_TEST_COUNT_ENV");
+    }
+
+    /// The plain positive form is present and no negation was given: `true`.
+    #[test]
+    fn negatable_switch_positive_form() {
+        let parser = long("feature").negatable_switch();
+        let (value, _) = (parser.parse)(args(&["--feature"])).unwrap();
+        assert!(value);
+    }
+
+    /// Only the auto-derived `--no-<name>` form is given: `false`.
+    #[test]
+    fn negatable_switch_negative_form() {
+        let parser = long("feature").negatable_switch();
+        let (value, _) = (parser.parse)(args(&["--no-feature"])).unwrap();
+        assert!(!value);
+    }
+
+    /// Whichever form appears later on the command line wins, regardless of order.
+    #[test]
+    fn negatable_switch_last_occurrence_wins() {
+        let parser = long("feature").negatable_switch();
+        let (value, _) = (parser.parse)(args(&["--feature", "--no-feature"])).unwrap();
+        assert!(!value);
+
+        let parser = long("feature").negatable_switch();
+        let (value, _) = (parser.parse)(args(&["--no-feature", "--feature"])).unwrap();
+        assert!(value);
+    }
+
+    /// Neither form present and no env fallback: defaults to `false`.
+    #[test]
+    fn negatable_switch_absent_defaults_to_false() {
+        let parser = long("feature").negatable_switch();
+        let (value, _) = (parser.parse)(args(&[])).unwrap();
+        assert!(!value);
+    }
+
+    /// A truthy env value is used only when neither form appears on the command line.
+    #[test]
+    fn negatable_switch_env_fallback() {
+        env::set_var("BPAF_TEST_NEGATABLE_ENV", "true");
+        let parser = long("feature").env("BPAF_TEST_NEGATABLE_ENV").negatable_switch();
+        let (value, _) = (parser.parse)(args(&[])).unwrap();
+        assert!(value);
+
+        let parser = long("feature").env("BPAF_TEST_NEGATABLE_ENV").negatable_switch();
+        let (value, _) = (parser.parse)(args(&["--no-feature"])).unwrap();
+        assert!(!value, "an explicit CLI negation must still win over env");
+        env::remove_var("BPAF_TEST_NEGATABLE_ENV");
+    }
+
+    /// `argument_with` parses a valid value into `T`.
+    #[test]
+    fn argument_with_parses_valid_value() {
+        let parser: Parser<u16> = long("port").argument_with("PORT");
+        let (value, _) = (parser.parse)(args(&["--port", "8080"])).unwrap();
+        assert_eq!(value, 8080);
+    }
+
+    /// `argument_with` reports a `FromStr` parse failure rather than panicking.
+    #[test]
+    fn argument_with_rejects_unparseable_value() {
+        let parser: Parser<u16> = long("port").argument_with("PORT");
+        let err = (parser.parse)(args(&["--port", "not-a-number"])).unwrap_err();
+        match err {
+            Error::Stdout(msg) => assert!(msg.contains("PORT"), "{:?}", msg),
+            other => panic!("expected a Stdout error, got {:?}", other),
+        }
+    }
+
+    /// A value inside the bounds is returned unchanged.
+    #[test]
+    fn range_accepts_value_inside_bounds() {
+        let parser: Parser<u16> = long("port").argument_with("PORT").range(1024..=65535);
+        let (value, _) = (parser.parse)(args(&["--port", "8080"])).unwrap();
+        assert_eq!(value, 8080);
+    }
+
+    /// The inclusive lower and upper bounds are both accepted.
+    #[test]
+    fn range_accepts_inclusive_edges() {
+        let parser: Parser<u16> = long("port").argument_with("PORT").range(1024..=65535);
+        let (value, _) = (parser.parse)(args(&["--port", "1024"])).unwrap();
+        assert_eq!(value, 1024);
+
+        let parser: Parser<u16> = long("port").argument_with("PORT").range(1024..=65535);
+        let (value, _) = (parser.parse)(args(&["--port", "65535"])).unwrap();
+        assert_eq!(value, 65535);
+    }
+
+    /// A value outside the bounds fails with the documented `"NN not in range A..=B"` wording.
+    #[test]
+    fn range_rejects_value_outside_bounds_with_exact_message() {
+        let parser: Parser<u16> = long("port").argument_with("PORT").range(1024..=65535);
+        let err = (parser.parse)(args(&["--port", "80"])).unwrap_err();
+        match err {
+            Error::Stdout(msg) => assert_eq!(msg, "80 not in range 1024..=65535"),
+            other => panic!("expected a Stdout error, got {:?}", other),
+        }
+    }
+
+    /// A restricted value that isn't in the list fails with the documented
+    /// `"invalid value '...', expected one of: ..."` wording.
+    #[test]
+    fn possible_values_rejects_value_with_exact_message() {
+        let parser = long("speed").argument("SPEED").possible_values(&["fast", "slow"]);
+        let err = (parser.parse)(args(&["--speed", "bogus"])).unwrap_err();
+        match err {
+            Error::Stdout(msg) => {
+                assert_eq!(msg, "invalid value 'bogus', expected one of: fast, slow")
+            }
+            other => panic!("expected a Stdout error, got {:?}", other),
+        }
+    }
+}